@@ -6,264 +6,441 @@
 
 use std::iter::Iterator;
 
-/// `Substr` is a string substring iterator.
-pub struct SubstrIterator<'a> {
-    s: &'a str,
-    needle: &'a str,
-    l: usize,
-    needle_len: usize,
-    start: usize,
-    emit_all: bool,
+/// `Pattern` describes a needle that can be searched for within a haystack,
+/// modeled on libcore's `str::pattern::Pattern`. Implementors produce a
+/// `Searcher` which reports match byte ranges within the haystack.
+pub trait Pattern<'a> {
+    /// The searcher produced for this pattern.
+    type Searcher: Searcher<'a>;
+
+    /// Constructs a searcher over `haystack` for this pattern.
+    fn into_searcher(self, haystack: &'a str) -> Self::Searcher;
 }
 
-/// `Func` defines the character function iterator trait.
-pub trait Substr<'a> {
-    /// Returns a character function iterator for the given string and character function.
-    fn substr_iter(&'a self, &'a str) -> SubstrIterator<'a>;
+/// `Searcher` reports successive pattern match byte ranges within a
+/// haystack, scanning forward from `from` up to `to`.
+pub trait Searcher<'a> {
+    /// Returns the next match as a `(start, end)` byte range within
+    /// `s[from..to]`, or `None` if the pattern does not occur there.
+    fn next_match(&self, s: &'a str, from: usize, to: usize) -> Option<(usize, usize)>;
 }
 
-impl<'a> Substr<'a> for str {
-    #[inline]
-    fn substr_iter(&'a self, needle: &'a str) -> SubstrIterator<'a> {
-        SubstrIterator {
-            s: self,
-            needle: needle,
-            l: self.len(),
-            needle_len: needle.len(),
-            start: 0,
-            emit_all: false,
-        }
-    }
+/// `ReverseSearcher` extends `Searcher` with the ability to scan backward,
+/// mirroring the `ReverseSearcher`/`DoubleEndedSearcher` split in libcore's
+/// `str::pattern` module.
+pub trait ReverseSearcher<'a>: Searcher<'a> {
+    /// Returns the last match as a `(start, end)` byte range within
+    /// `s[from..to]`, or `None` if the pattern does not occur there.
+    fn next_match_back(&self, s: &'a str, from: usize, to: usize) -> Option<(usize, usize)>;
 }
 
-impl<'a> Iterator for SubstrIterator<'a> {
-    type Item = &'a str;
+/// `SubstrSearcher` finds literal occurrences of a substring needle.
+pub struct SubstrSearcher<'a> {
+    needle: &'a str,
+}
 
+impl<'a> Searcher<'a> for SubstrSearcher<'a> {
     #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.start > self.l {
+    fn next_match(&self, s: &'a str, from: usize, to: usize) -> Option<(usize, usize)> {
+        let needle_len = self.needle.len();
+
+        // An empty needle has no occurrences to delimit on; fall back to
+        // a zero-width match after each char, so every char is emitted as
+        // its own segment (mirrors `char_next_match`'s per-char scan).
+        if needle_len == 0 {
+            if from >= to {
+                return None;
+            }
+
+            let l = utf8_char_width(s.as_bytes()[from]);
+
+            return Some((from + l, from + l));
+        }
+
+        if to < from + needle_len {
             return None;
         }
 
-        if self.needle_len == 0 {
-            return self.next_char();
+        for i in from..=to - needle_len {
+            if s.is_char_boundary(i)
+                && s.is_char_boundary(i + needle_len)
+                && &s[i..i + needle_len] == self.needle
+            {
+                return Some((i, i + needle_len));
+            }
         }
 
-        if self.l == 0 {
-            if !self.emit_all {
+        None
+    }
+}
+
+impl<'a> ReverseSearcher<'a> for SubstrSearcher<'a> {
+    #[inline]
+    fn next_match_back(&self, s: &'a str, from: usize, to: usize) -> Option<(usize, usize)> {
+        let needle_len = self.needle.len();
+
+        if needle_len == 0 {
+            if from >= to {
                 return None;
             }
 
-            self.start = self.l + 1;
+            let i = prev_char_boundary(s.as_bytes(), to);
 
-            return Some(self.s);
+            return Some((i, i));
         }
 
-        let mut has_match = false;
+        if to < from + needle_len {
+            return None;
+        }
 
-        for i in self.start..self.l {
-            let end = i + self.needle_len;
+        let mut i = to - needle_len;
 
-            if end > self.l {
-                break;
+        loop {
+            if s.is_char_boundary(i)
+                && s.is_char_boundary(i + needle_len)
+                && &s[i..i + needle_len] == self.needle
+            {
+                return Some((i, i + needle_len));
             }
 
-            if &self.s[i..end] != self.needle {
-                // Within a value range; continue reading
-                if has_match {
-                    continue;
-                }
+            if i == from {
+                return None;
+            }
 
-                self.start = i;
-                has_match = true;
+            i -= 1;
+        }
+    }
+}
 
-                continue;
-            }
+impl<'a> Pattern<'a> for &'a str {
+    type Searcher = SubstrSearcher<'a>;
 
-            if !self.emit_all && !has_match {
-                self.start = i;
+    #[inline]
+    fn into_searcher(self, _haystack: &'a str) -> Self::Searcher {
+        SubstrSearcher { needle: self }
+    }
+}
 
-                continue;
-            }
+/// `CharSearcher` finds occurrences of a single codepoint.
+pub struct CharSearcher {
+    needle: char,
+}
 
-            // Emit current value
-            let v = &self.s[self.start..i];
-            self.start = i + self.needle_len;
+impl<'a> Searcher<'a> for CharSearcher {
+    #[inline]
+    fn next_match(&self, s: &'a str, from: usize, to: usize) -> Option<(usize, usize)> {
+        char_next_match(s, from, to, |c| c == self.needle)
+    }
+}
 
-            return Some(v);
-        }
+impl<'a> ReverseSearcher<'a> for CharSearcher {
+    #[inline]
+    fn next_match_back(&self, s: &'a str, from: usize, to: usize) -> Option<(usize, usize)> {
+        char_next_match_back(s, from, to, |c| c == self.needle)
+    }
+}
 
-        if !has_match {
-            if !self.emit_all || &self.s[self.l - self.needle_len..] != self.needle {
-                return None;
-            }
+impl<'a> Pattern<'a> for char {
+    type Searcher = CharSearcher;
 
-            let v = &self.s[self.start..];
-            self.start = self.l + 1;
+    #[inline]
+    fn into_searcher(self, _haystack: &'a str) -> Self::Searcher {
+        CharSearcher { needle: self }
+    }
+}
 
-            return Some(v);
-        }
+/// `CharSliceSearcher` finds occurrences of any codepoint from a set.
+pub struct CharSliceSearcher<'a> {
+    needles: &'a [char],
+}
 
-        // Emit remaing value
-        let v = &self.s[self.start..];
-        self.start += self.l;
+impl<'a> Searcher<'a> for CharSliceSearcher<'a> {
+    #[inline]
+    fn next_match(&self, s: &'a str, from: usize, to: usize) -> Option<(usize, usize)> {
+        char_next_match(s, from, to, |c| self.needles.contains(&c))
+    }
+}
 
-        Some(v)
+impl<'a> ReverseSearcher<'a> for CharSliceSearcher<'a> {
+    #[inline]
+    fn next_match_back(&self, s: &'a str, from: usize, to: usize) -> Option<(usize, usize)> {
+        char_next_match_back(s, from, to, |c| self.needles.contains(&c))
     }
+}
+
+impl<'a> Pattern<'a> for &'a [char] {
+    type Searcher = CharSliceSearcher<'a>;
 
     #[inline]
-    fn count(self) -> usize {
-        let mut v = 0;
+    fn into_searcher(self, _haystack: &'a str) -> Self::Searcher {
+        CharSliceSearcher { needles: self }
+    }
+}
 
-        for _ in self {
-            v += 1;
-        }
+/// `FnSearcher` finds the next char for which the given function returns `true`.
+pub struct FnSearcher<F> {
+    f: F,
+}
 
-        v
+impl<'a, F> Searcher<'a> for FnSearcher<F>
+where
+    F: Fn(char) -> bool,
+{
+    #[inline]
+    fn next_match(&self, s: &'a str, from: usize, to: usize) -> Option<(usize, usize)> {
+        char_next_match(s, from, to, |c| (self.f)(c))
     }
+}
 
+impl<'a, F> ReverseSearcher<'a> for FnSearcher<F>
+where
+    F: Fn(char) -> bool,
+{
     #[inline]
-    fn for_each<F>(self, mut f: F)
-    where
-        F: FnMut(Self::Item),
-    {
-        for v in self {
-            f(v)
-        }
+    fn next_match_back(&self, s: &'a str, from: usize, to: usize) -> Option<(usize, usize)> {
+        char_next_match_back(s, from, to, |c| (self.f)(c))
     }
 }
 
-impl<'a> SubstrIterator<'a> {
-    /// Returns an iterator which emits all values; emulating string `split` methods.
+impl<'a, F> Pattern<'a> for F
+where
+    F: Fn(char) -> bool,
+{
+    type Searcher = FnSearcher<F>;
+
     #[inline]
-    pub fn all(mut self) -> SubstrIterator<'a> {
-        self.emit_all = true;
+    fn into_searcher(self, _haystack: &'a str) -> Self::Searcher {
+        FnSearcher { f: self }
+    }
+}
 
-        self
+/// Returns the byte width of the UTF-8 char starting with the leading byte `b`.
+#[inline]
+fn utf8_char_width(b: u8) -> usize {
+    match b {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        _ => 4,
     }
+}
 
-    #[inline]
-    fn next_char(&mut self) -> Option<&'a str> {
-        if self.l == 0 || self.start == self.l {
-            return None;
+/// Scans `s[from..to]` forward, char by char, for the first char matching `pred`.
+///
+/// Walks a raw byte cursor and decodes only the bytes of the char under
+/// inspection, rather than re-slicing the remaining tail on every step.
+#[inline]
+fn char_next_match<P>(s: &str, from: usize, to: usize, pred: P) -> Option<(usize, usize)>
+where
+    P: Fn(char) -> bool,
+{
+    let bytes = s.as_bytes();
+    let mut i = from;
+
+    while i < to {
+        let l = utf8_char_width(bytes[i]);
+        let c = s[i..i + l].chars().next().unwrap();
+
+        if pred(c) {
+            return Some((i, i + l));
         }
 
-        let c = self.s[self.start..].chars().next().unwrap();
-        let l = c.len_utf8();
-        let end = self.start + l;
-        let v = &self.s[self.start..end];
+        i += l;
+    }
+
+    None
+}
+
+/// Returns the byte index of the char boundary immediately before `i`,
+/// stepping back over any UTF-8 continuation bytes.
+#[inline]
+fn prev_char_boundary(bytes: &[u8], i: usize) -> usize {
+    let mut j = i;
 
-        self.start = end;
+    loop {
+        j -= 1;
 
-        Some(v)
+        if bytes[j] & 0xc0 != 0x80 {
+            return j;
+        }
     }
+}
 
-    /// Resets the iterator to the start position.
-    #[inline]
-    pub fn reset(&mut self) {
-        self.start = 0;
+/// Scans `s[from..to]` backward, char by char, for the last char matching `pred`.
+#[inline]
+fn char_next_match_back<P>(s: &str, from: usize, to: usize, pred: P) -> Option<(usize, usize)>
+where
+    P: Fn(char) -> bool,
+{
+    let bytes = s.as_bytes();
+    let mut i = to;
+
+    while i > from {
+        let j = prev_char_boundary(bytes, i);
+        let c = s[j..i].chars().next().unwrap();
+
+        if pred(c) {
+            return Some((j, i));
+        }
+
+        i = j;
     }
+
+    None
 }
 
-/// `FuncIterator` is a character function iterator.
-pub struct FuncIterator<'a> {
-    f: fn(char) -> bool,
+/// `SplitIter` splits a haystack into segments separated by pattern matches.
+/// By default consecutive and leading/trailing empty segments are skipped;
+/// call `.all()` to emit every segment, emulating string `split` methods.
+pub struct SplitIter<'a, S> {
     s: &'a str,
+    searcher: S,
     l: usize,
     start: usize,
+    end: usize,
+    emit_all: bool,
 }
 
-/// `Func` defines the character function iterator trait.
-pub trait Func<'a> {
-    /// Returns a character function iterator for the given string and character function.
-    fn func_iter(&'a self, fn(char) -> bool) -> FuncIterator<'a>;
+/// `Split` defines the generic pattern-based splitting iterator trait.
+pub trait Split<'a> {
+    /// Returns an iterator over the segments of `self` separated by matches of `pat`.
+    fn split_iter<P: Pattern<'a>>(&'a self, pat: P) -> SplitIter<'a, P::Searcher>;
 }
 
-impl<'a> Func<'a> for str {
+impl<'a> Split<'a> for str {
     #[inline]
-    fn func_iter(&'a self, f: fn(char) -> bool) -> FuncIterator<'a> {
-        FuncIterator {
-            f: f,
+    fn split_iter<P: Pattern<'a>>(&'a self, pat: P) -> SplitIter<'a, P::Searcher> {
+        SplitIter {
             s: self,
+            searcher: pat.into_searcher(self),
             l: self.len(),
             start: 0,
+            end: self.len(),
+            emit_all: false,
         }
     }
 }
 
-/// `Word` defines the words iterator trait.
-pub trait Word<'a> {
-    /// Returns a words iterator for the given string.
-    fn word_iter(&'a self) -> FuncIterator<'a>;
-}
+impl<'a, S> SplitIter<'a, S> {
+    /// Returns an iterator which emits all segments; emulating string `split` methods.
+    #[inline]
+    pub fn all(mut self) -> SplitIter<'a, S> {
+        self.emit_all = true;
 
-#[inline]
-fn word_iter<'a>(s: &'a str) -> FuncIterator<'a> {
-    s.func_iter(|c: char| !c.is_alphanumeric())
-}
+        self
+    }
 
-impl<'a> Word<'a> for str {
+    /// Resets the iterator to the start position.
     #[inline]
-    fn word_iter(&'a self) -> FuncIterator<'a> {
-        word_iter(self)
+    pub fn reset(&mut self) {
+        self.start = 0;
+        self.end = self.l;
+    }
+
+    /// Returns an adapter which only yields segments matching `list`.
+    #[inline]
+    pub fn matching(self, list: MatcherList<'a>) -> Matching<'a, S> {
+        Matching { iter: self, list }
     }
 }
 
-impl<'a> Iterator for FuncIterator<'a> {
+impl<'a, S> Iterator for SplitIter<'a, S>
+where
+    S: Searcher<'a>,
+{
     type Item = &'a str;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.start == self.l {
-            return None;
-        }
-
-        let mut i = self.start;
-        let mut has_match = false;
+        loop {
+            if self.start > self.end {
+                return None;
+            }
 
-        while i < self.l {
-            let c = self.s[i..].chars().next().unwrap();
-            let l = c.len_utf8();
+            match self.searcher.next_match(self.s, self.start, self.end) {
+                Some((m0, m1)) => {
+                    let v = &self.s[self.start..m0];
+                    self.start = m1;
 
-            if (self.f)(c) {
-                if has_match {
-                    let v = &self.s[self.start..i];
-                    self.start = i + l;
+                    if !self.emit_all && v.is_empty() {
+                        continue;
+                    }
 
                     return Some(v);
                 }
+                None => {
+                    let v = &self.s[self.start..self.end];
+                    self.start = self.end + 1;
 
-                self.start = i + l;
-                has_match = false;
-            } else if !has_match {
-                self.start = i;
-                has_match = true;
-            }
+                    if !self.emit_all && v.is_empty() {
+                        return None;
+                    }
 
-            i += l;
+                    return Some(v);
+                }
+            }
         }
+    }
 
-        if has_match && self.start < self.l {
-            let v = &self.s[self.start..];
-            self.start += self.l - self.start;
-
-            return Some(v);
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.start > self.end {
+            (0, Some(0))
+        } else {
+            (0, Some(self.end - self.start + 1))
         }
-
-        None
     }
 
     #[inline]
-    fn count(self) -> usize {
-        let mut v = 0;
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        // Runs the scan loop directly over the destructured fields instead
+        // of looping on `next()`, avoiding the repeated `&mut self`
+        // indirection and `Option` wrapping of the per-item path.
+        let SplitIter {
+            s,
+            searcher,
+            start,
+            end,
+            emit_all,
+            ..
+        } = self;
+        let mut start = start;
+        let mut acc = init;
+
+        while start <= end {
+            match searcher.next_match(s, start, end) {
+                Some((m0, m1)) => {
+                    let v = &s[start..m0];
+                    start = m1;
+
+                    if !emit_all && v.is_empty() {
+                        continue;
+                    }
+
+                    acc = f(acc, v);
+                }
+                None => {
+                    let v = &s[start..end];
 
-        for _ in self {
-            v += 1;
+                    if emit_all || !v.is_empty() {
+                        acc = f(acc, v);
+                    }
+
+                    break;
+                }
+            }
         }
 
-        v
+        acc
     }
 
+    // `try_fold` is not overridden: a generic override requires bounding `R`
+    // by `std::ops::Try`, which is still unstable (rust-lang/rust#84277), so
+    // callers fall back to the default implementation built on `next`.
+
     #[inline]
     fn for_each<F>(self, mut f: F)
     where
@@ -275,19 +452,239 @@ impl<'a> Iterator for FuncIterator<'a> {
     }
 }
 
-impl<'a> FuncIterator<'a> {
-    /// Resets the iterator to the start position.
+impl<'a, S> DoubleEndedIterator for SplitIter<'a, S>
+where
+    S: ReverseSearcher<'a>,
+{
     #[inline]
-    pub fn reset(&mut self) {
-        self.start = 0;
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.start > self.end {
+                return None;
+            }
+
+            match self.searcher.next_match_back(self.s, self.start, self.end) {
+                Some((m0, m1)) => {
+                    let v = &self.s[m1..self.end];
+                    self.end = m0;
+
+                    if !self.emit_all && v.is_empty() {
+                        continue;
+                    }
+
+                    return Some(v);
+                }
+                None => {
+                    let v = &self.s[self.start..self.end];
+                    self.start = self.end + 1;
+
+                    if !self.emit_all && v.is_empty() {
+                        return None;
+                    }
+
+                    return Some(v);
+                }
+            }
+        }
+    }
+}
+
+/// `Combiner` selects how a `MatcherList`'s items are combined.
+pub enum Combiner {
+    /// All items must match.
+    And,
+    /// Any item must match.
+    Or,
+}
+
+/// `Matcher` tests a single condition against a segment, inspired by cdx's `Matcher`.
+pub enum Matcher<'a> {
+    /// Segment starts with the given prefix.
+    Prefix(&'a str),
+    /// Segment ends with the given suffix.
+    Suffix(&'a str),
+    /// Segment contains the given substring.
+    Contains(&'a str),
+    /// Segment equals the given string exactly.
+    Exact(&'a str),
+    /// Every char in the segment satisfies the given function.
+    CharFn(fn(char) -> bool),
+    /// Segment matches the given glob pattern (`*` and `?` wildcards).
+    Glob(&'a str),
+}
+
+impl<'a> Matcher<'a> {
+    /// Reports whether `s` satisfies this matcher.
+    #[inline]
+    pub fn ok(&self, s: &str) -> bool {
+        match *self {
+            Matcher::Prefix(v) => s.starts_with(v),
+            Matcher::Suffix(v) => s.ends_with(v),
+            Matcher::Contains(v) => s.contains(v),
+            Matcher::Exact(v) => s == v,
+            Matcher::CharFn(f) => s.chars().all(f),
+            Matcher::Glob(pattern) => glob_match(pattern, s),
+        }
+    }
+}
+
+/// Reports whether `text` matches `pattern`, where `*` matches any run of
+/// characters (including none) and `?` matches exactly one character. Uses
+/// the classic two-pointer backtracking algorithm, stepping over whole
+/// chars so multi-byte UTF-8 is handled correctly.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+
+    let (mut pi, mut ti) = (0, 0);
+    let mut star_p = None;
+    let mut star_t = 0;
+
+    while ti < t.len() {
+        if pi < p.len() && p[pi] == '*' {
+            star_p = Some(pi);
+            star_t = ti;
+            pi += 1;
+        } else if pi < p.len() && (p[pi] == '?' || p[pi] == t[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if let Some(sp) = star_p {
+            pi = sp + 1;
+            star_t += 1;
+            ti = star_t;
+        } else {
+            return false;
+        }
+    }
+
+    while pi < p.len() && p[pi] == '*' {
+        pi += 1;
+    }
+
+    pi == p.len()
+}
+
+/// `MatcherList` combines a set of `Matcher`s with `And`/`Or` semantics,
+/// mirroring cdx's `MatcherList`.
+pub struct MatcherList<'a> {
+    combiner: Combiner,
+    items: Vec<Matcher<'a>>,
+}
+
+impl<'a> MatcherList<'a> {
+    /// Constructs a `MatcherList` combining `items` via `combiner`.
+    #[inline]
+    pub fn new(combiner: Combiner, items: Vec<Matcher<'a>>) -> MatcherList<'a> {
+        MatcherList { combiner, items }
+    }
+
+    /// Reports whether `s` satisfies this list, short-circuiting evaluation.
+    #[inline]
+    pub fn ok(&self, s: &str) -> bool {
+        match self.combiner {
+            Combiner::And => self.items.iter().all(|m| m.ok(s)),
+            Combiner::Or => self.items.iter().any(|m| m.ok(s)),
+        }
+    }
+}
+
+/// `Matching` filters the segments of a `SplitIter`, only yielding those
+/// satisfying a `MatcherList`.
+pub struct Matching<'a, S> {
+    iter: SplitIter<'a, S>,
+    list: MatcherList<'a>,
+}
+
+impl<'a, S> Iterator for Matching<'a, S>
+where
+    S: Searcher<'a>,
+{
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let list = &self.list;
+
+        self.iter.find(|v| list.ok(v))
+    }
+}
+
+impl<'a, S> DoubleEndedIterator for Matching<'a, S>
+where
+    S: ReverseSearcher<'a>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some(v) = self.iter.next_back() {
+            if self.list.ok(v) {
+                return Some(v);
+            }
+        }
+
+        None
+    }
+}
+
+/// Iterator produced by [`Substr::substr_iter`].
+pub type SubstrIterator<'a> = SplitIter<'a, SubstrSearcher<'a>>;
+
+/// `Substr` defines the substring iterator trait.
+pub trait Substr<'a> {
+    /// Returns a substring iterator for the given string and needle.
+    fn substr_iter(&'a self, needle: &'a str) -> SubstrIterator<'a>;
+}
+
+impl<'a> Substr<'a> for str {
+    #[inline]
+    fn substr_iter(&'a self, needle: &'a str) -> SubstrIterator<'a> {
+        self.split_iter(needle)
+    }
+}
+
+/// Iterator produced by [`Func::func_iter`] and [`Word::word_iter`].
+pub type FuncIterator<'a> = SplitIter<'a, FnSearcher<fn(char) -> bool>>;
+
+/// `Func` defines the character function iterator trait.
+pub trait Func<'a> {
+    /// Returns a character function iterator for the given string and character function.
+    fn func_iter(&'a self, f: fn(char) -> bool) -> FuncIterator<'a>;
+}
+
+impl<'a> Func<'a> for str {
+    #[inline]
+    fn func_iter(&'a self, f: fn(char) -> bool) -> FuncIterator<'a> {
+        self.split_iter(f)
+    }
+}
+
+/// `Word` defines the words iterator trait.
+pub trait Word<'a> {
+    /// Returns a words iterator for the given string.
+    fn word_iter(&'a self) -> FuncIterator<'a>;
+}
+
+#[inline]
+fn word_iter<'a>(s: &'a str) -> FuncIterator<'a> {
+    s.func_iter(|c: char| !c.is_alphanumeric())
+}
+
+impl<'a> Word<'a> for str {
+    #[inline]
+    fn word_iter(&'a self) -> FuncIterator<'a> {
+        word_iter(self)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use Combiner;
     use Func;
+    use Matcher;
+    use MatcherList;
+    use Split;
     use Substr;
     use Word;
+    use glob_match;
 
     #[test]
     fn func_count() {
@@ -305,10 +702,149 @@ mod tests {
         assert_eq!(2, c);
     }
 
+    #[test]
+    fn substr_empty_needle() {
+        let v: Vec<&str> = "abc".substr_iter("").collect();
+
+        assert_eq!(vec!["a", "b", "c"], v);
+    }
+
+    #[test]
+    fn substr_empty_needle_rev() {
+        let v: Vec<&str> = "abc".substr_iter("").rev().collect();
+
+        assert_eq!(vec!["c", "b", "a"], v);
+    }
+
+    #[test]
+    fn substr_multibyte_needle() {
+        let v: Vec<&str> = "héllo héllo".substr_iter("é").collect();
+
+        assert_eq!(vec!["h", "llo h", "llo"], v);
+    }
+
+    #[test]
+    fn substr_multibyte_needle_rev() {
+        let v: Vec<&str> = "héllo héllo".substr_iter("é").rev().collect();
+
+        assert_eq!(vec!["llo", "llo h", "h"], v);
+    }
+
     #[test]
     fn word_count() {
         let c = "1 2 3 a b c".word_iter().count();
 
         assert_eq!(6, c);
     }
+
+    #[test]
+    fn substr_rev() {
+        let v: Vec<&str> = "a,b,c".substr_iter(",").all().rev().collect();
+
+        assert_eq!(vec!["c", "b", "a"], v);
+    }
+
+    #[test]
+    fn substr_double_ended() {
+        let mut it = "a,b,c,d".substr_iter(",").all();
+
+        assert_eq!(Some("a"), it.next());
+        assert_eq!(Some("d"), it.next_back());
+        assert_eq!(Some("b"), it.next());
+        assert_eq!(Some("c"), it.next_back());
+        assert_eq!(None, it.next());
+        assert_eq!(None, it.next_back());
+    }
+
+    #[test]
+    fn word_rev() {
+        let v: Vec<&str> = "1 2 3".word_iter().rev().collect();
+
+        assert_eq!(vec!["3", "2", "1"], v);
+    }
+
+    #[test]
+    fn split_iter_char() {
+        let v: Vec<&str> = "a,b,,c".split_iter(',').collect();
+
+        assert_eq!(vec!["a", "b", "c"], v);
+    }
+
+    #[test]
+    fn split_iter_char_slice() {
+        let delims = ['\t', ' '];
+        let v: Vec<&str> = "a b\tc".split_iter(&delims[..]).collect();
+
+        assert_eq!(vec!["a", "b", "c"], v);
+    }
+
+    #[test]
+    fn split_iter_closure() {
+        let v: Vec<&str> = "a1b2c3".split_iter(|c: char| c.is_numeric()).collect();
+
+        assert_eq!(vec!["a", "b", "c"], v);
+    }
+
+    #[test]
+    fn matching_and() {
+        let list = MatcherList::new(
+            Combiner::And,
+            vec![Matcher::Prefix("user_"), Matcher::Suffix("_admin")],
+        );
+        let v: Vec<&str> = "user_bob,user_admin,user_alice_admin"
+            .split_iter(',')
+            .matching(list)
+            .collect();
+
+        assert_eq!(vec!["user_admin", "user_alice_admin"], v);
+    }
+
+    #[test]
+    fn matching_or() {
+        let list = MatcherList::new(
+            Combiner::Or,
+            vec![Matcher::Exact("b"), Matcher::Contains("x")],
+        );
+        let v: Vec<&str> = "a,b,xc,d".split_iter(',').matching(list).collect();
+
+        assert_eq!(vec!["b", "xc"], v);
+    }
+
+    #[test]
+    fn glob_match_basic() {
+        assert!(glob_match("", ""));
+        assert!(!glob_match("", "a"));
+        assert!(glob_match("user_*", "user_bob"));
+        assert!(!glob_match("user_*", "admin_bob"));
+        assert!(glob_match("a?c", "abc"));
+        assert!(!glob_match("a?c", "ac"));
+        assert!(glob_match("*", "anything"));
+        assert!(glob_match("h*llo", "hello"));
+        assert!(glob_match("h*llo", "heeello"));
+    }
+
+    #[test]
+    fn matching_glob() {
+        let list = MatcherList::new(Combiner::And, vec![Matcher::Glob("user_*")]);
+        let v: Vec<&str> = "user_bob,admin_bob,user_alice"
+            .split_iter(',')
+            .matching(list)
+            .collect();
+
+        assert_eq!(vec!["user_bob", "user_alice"], v);
+    }
+
+    #[test]
+    fn substr_size_hint() {
+        let it = "a,b,c".substr_iter(",");
+
+        assert_eq!((0, Some(6)), it.size_hint());
+    }
+
+    #[test]
+    fn substr_fold() {
+        let c = "a,b,c".substr_iter(",").fold(0, |acc, _| acc + 1);
+
+        assert_eq!(3, c);
+    }
 }